@@ -1,12 +1,26 @@
 use crate::types::Context;
 
-#[must_use]
-pub fn is_moderator(_: Context<'_>) -> bool {
-    true
+/// Resolves the invoking member and checks whether they are a moderator, either via the
+/// configured moderator role or via one of the Discord permissions that imply moderation
+/// powers. Fails closed (returns `false`) if the command wasn't invoked from a guild, e.g. in
+/// a DM, since there's no member to check permissions for.
+pub async fn is_moderator(ctx: Context<'_>) -> anyhow::Result<bool> {
+    let Some(member) = ctx.author_member().await else {
+        return Ok(false);
+    };
+
+    if let Some(moderator_role_id) = ctx.data().moderator_role_id {
+        if member.roles.contains(&moderator_role_id) {
+            return Ok(true);
+        }
+    }
+
+    let permissions = member.permissions(ctx)?;
+    Ok(permissions.ban_members() || permissions.manage_messages() || permissions.administrator())
 }
 
 pub async fn check_is_moderator(ctx: Context<'_>) -> anyhow::Result<bool> {
-    let user_has_moderator_role = is_moderator(ctx);
+    let user_has_moderator_role = is_moderator(ctx).await?;
     if !user_has_moderator_role {
         ctx.send(
             poise::CreateReply::default().content("This command is only available to moderators.").ephemeral(true),
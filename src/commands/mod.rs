@@ -0,0 +1,8 @@
+pub mod crates;
+pub mod godbolt;
+pub mod man;
+pub mod playground;
+pub mod remind;
+pub mod settings;
+pub mod thread_pin;
+pub mod utilities;
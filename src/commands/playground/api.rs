@@ -0,0 +1,53 @@
+//! Types mirroring the Rust Playground's `/execute` JSON API.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrateType {
+    Binary,
+    #[serde(rename = "lib")]
+    Library,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum Edition {
+    #[serde(rename = "2015")]
+    E2015,
+    #[serde(rename = "2018")]
+    E2018,
+    #[serde(rename = "2021")]
+    E2021,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    Debug,
+    Release,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlaygroundRequest<'a> {
+    pub code: &'a str,
+    pub channel: Channel,
+    pub crate_type: CrateType,
+    pub edition: Edition,
+    pub mode: Mode,
+    pub tests: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayResult {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
@@ -1,5 +1,6 @@
 use anyhow::Error;
 use core::fmt::Write as _;
+use std::str::FromStr;
 use syn::{parse_file, Item, ItemFn, Visibility};
 
 use crate::types::Context;
@@ -12,6 +13,142 @@ use super::{
     },
 };
 
+/// Optimization level to benchmark (and, with `asm=true`, to compile the Godbolt assembly
+/// output under), mirroring `rustc`'s `-C opt-level` values.
+#[derive(Debug, Clone, Copy)]
+enum OptLevel {
+    O0,
+    O1,
+    O2,
+    O3,
+    S,
+    Z,
+}
+
+impl Default for OptLevel {
+    fn default() -> Self {
+        Self::O3
+    }
+}
+
+impl FromStr for OptLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(Self::O0),
+            "1" => Ok(Self::O1),
+            "2" => Ok(Self::O2),
+            "3" => Ok(Self::O3),
+            "s" => Ok(Self::S),
+            "z" => Ok(Self::Z),
+            _ => Err(()),
+        }
+    }
+}
+
+impl OptLevel {
+    fn rustc_opt_level(self) -> &'static str {
+        match self {
+            Self::O0 => "0",
+            Self::O1 => "1",
+            Self::O2 => "2",
+            Self::O3 => "3",
+            Self::S => "s",
+            Self::Z => "z",
+        }
+    }
+
+    /// The benchmark itself can only be compiled in debug or release mode via the Playground
+    /// API; `opt-level=0` maps onto a debug build, anything higher onto release.
+    fn benchmark_mode(self) -> Mode {
+        match self {
+            Self::O0 => Mode::Debug,
+            _ => Mode::Release,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct GodboltCompileRequest<'a> {
+    source: &'a str,
+    options: GodboltCompileOptions,
+}
+
+#[derive(serde::Serialize)]
+struct GodboltCompileOptions {
+    #[serde(rename = "userArguments")]
+    user_arguments: String,
+    filters: GodboltCompileFilters,
+}
+
+#[derive(serde::Serialize)]
+struct GodboltCompileFilters {
+    labels: bool,
+    directives: bool,
+    comments: bool,
+}
+
+#[derive(serde::Deserialize)]
+struct GodboltCompileResponse {
+    asm: Vec<GodboltAsmLine>,
+}
+
+#[derive(serde::Deserialize)]
+struct GodboltAsmLine {
+    text: String,
+}
+
+/// Compiles `function_source` on Godbolt at the given optimization level and returns the
+/// resulting assembly, reusing the same HTTP client and compiler metadata as the `godbolt`
+/// family of commands.
+async fn fetch_function_asm(ctx: Context<'_>, function_source: &str, opt: OptLevel) -> anyhow::Result<String> {
+    let compiler_id = ctx.data().godbolt_metadata.lock().unwrap().default_rust_compiler_id().to_owned();
+
+    let response: GodboltCompileResponse = ctx
+        .data()
+        .http
+        .post(format!("https://godbolt.org/api/compiler/{compiler_id}/compile"))
+        .json(&GodboltCompileRequest {
+            source: function_source,
+            options: GodboltCompileOptions {
+                user_arguments: format!("-C opt-level={}", opt.rustc_opt_level()),
+                filters: GodboltCompileFilters { labels: true, directives: false, comments: false },
+            },
+        })
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    Ok(response.asm.into_iter().map(|line| line.text).collect::<Vec<_>>().join("\n"))
+}
+
+/// Parses the `asm` and `opt` flags that are specific to `microbench`, leaving everything
+/// else for the shared [`parse_flags`].
+fn parse_microbench_flags(args: &mut poise::KeyValueArgs) -> (bool, OptLevel, String) {
+    let mut asm = false;
+    let mut opt = OptLevel::default();
+    let mut errors = String::new();
+
+    for (key, value) in &args.0 {
+        match key.as_str() {
+            "asm" => match value.parse() {
+                Ok(value) => asm = value,
+                Err(_) => errors.push_str(&format!("Invalid value for `asm`: `{value}`\n")),
+            },
+            "opt" => match value.parse() {
+                Ok(value) => opt = value,
+                Err(()) => errors.push_str(&format!("Invalid opt level `{value}`, expected 0-3, s or z\n")),
+            },
+            _ => {}
+        }
+    }
+    args.0.retain(|key, _| key != "asm" && key != "opt");
+
+    (asm, opt, errors)
+}
+
 const BENCH_FUNCTION: &str = r#"
 fn bench(functions: &[(&str, fn())]) {
     const CHUNK_SIZE: usize = 1000;
@@ -94,7 +231,11 @@ pub async fn microbench(ctx: Context<'_>, flags: poise::KeyValueArgs, code: pois
     // final assembled code
     let code = hoise_crate_attributes(user_code, after_crate_attrs, &after_code);
 
-    let (flags, mut flag_parse_errors) = parse_flags(flags);
+    let mut flags = flags;
+    let (asm, opt, mut flag_parse_errors) = parse_microbench_flags(&mut flags);
+    let (flags, parse_errors) = parse_flags(flags);
+    flag_parse_errors += &parse_errors;
+
     let mut result: PlayResult = ctx
         .data()
         .http
@@ -104,7 +245,7 @@ pub async fn microbench(ctx: Context<'_>, flags: poise::KeyValueArgs, code: pois
             channel: flags.channel,
             crate_type: CrateType::Binary,
             edition: flags.edition,
-            mode: Mode::Release, // benchmarks on debug don't make sense
+            mode: opt.benchmark_mode(), // benchmarks on debug don't make sense, so `opt=0` is the only way in
             tests: false,
         })
         .send()
@@ -117,6 +258,21 @@ pub async fn microbench(ctx: Context<'_>, flags: poise::KeyValueArgs, code: pois
     if black_box_hint {
         flag_parse_errors += "Hint: use the black_box function to prevent computations from being optimized out\n";
     }
+
+    if asm {
+        for (function_name, function_source) in extract_pub_fns_from_user_code(user_code) {
+            // Functions lifted out on their own lose the `use std::hint::black_box;` that
+            // `hoise_crate_attributes` prepends to the assembled benchmark, so re-add it here.
+            let function_source = format!("{after_crate_attrs}{function_source}");
+            match fetch_function_asm(ctx, &function_source, opt).await {
+                Ok(asm) => {
+                    let _ = writeln!(result.stdout, "\n{function_name}:\n{asm}");
+                }
+                Err(e) => flag_parse_errors += &format!("Failed to fetch assembly for `{function_name}`: {e}\n"),
+            }
+        }
+    }
+
     send_reply(ctx, result, &code, &flags, &flag_parse_errors).await
 }
 
@@ -132,7 +288,12 @@ passed. Measurements are averaged and standard deviation is calculated for each
 Use the `std::hint::black_box` function, which is already imported, to wrap results of \
 computations that shouldn't be optimized out. Also wrap computation inputs in `black_box(...)` \
 that should be opaque to the optimizer: `number * 2` produces optimized integer doubling assembly while \
-`number * black_box(2)` produces a generic integer multiplication instruction",
+`number * black_box(2)` produces a generic integer multiplication instruction
+
+Pass `opt=0|1|2|3|s|z` to pick an optimization level (default `3`). The benchmark itself is only \
+built in debug mode (`opt=0`) or release mode (any other value). Pass `asm=true` to also show the \
+Godbolt assembly for each function compiled with exactly that `-C opt-level`, so you can see why \
+two functions differ in speed rather than just by how much",
         mode_and_channel: false,
         warn: true,
         run: false,
@@ -149,6 +310,14 @@ pub fn mul() {
 }
 
 fn extract_pub_fn_names_from_user_code(code: &str) -> Vec<String> {
+    extract_pub_fns_from_user_code(code).into_iter().map(|(name, _)| name).collect()
+}
+
+/// Returns the name and standalone source of each top-level `pub fn` in `code`, so each one
+/// can be sent to Godbolt on its own.
+fn extract_pub_fns_from_user_code(code: &str) -> Vec<(String, String)> {
+    use quote::ToTokens as _;
+
     let Ok(file) = parse_file(code) else {
         return vec![];
     };
@@ -156,9 +325,9 @@ fn extract_pub_fn_names_from_user_code(code: &str) -> Vec<String> {
     file.items
         .iter()
         .filter_map(|item| {
-            if let Item::Fn(ItemFn { vis, sig, .. }) = item {
+            if let Item::Fn(item_fn @ ItemFn { vis, sig, .. }) = item {
                 if matches!(vis, Visibility::Public(_)) {
-                    return Some(sig.ident.to_string());
+                    return Some((sig.ident.to_string(), item_fn.into_token_stream().to_string()));
                 }
             }
             None
@@ -0,0 +1,5 @@
+mod api;
+mod microbench;
+mod util;
+
+pub use microbench::{microbench, microbench_help};
@@ -0,0 +1,206 @@
+use crate::types::Context;
+use anyhow::Error;
+use poise::KeyValueArgs;
+
+use super::api::{Channel, Edition, Mode, PlayResult};
+
+/// The maximum length of a single Discord message.
+const DISCORD_MESSAGE_LIMIT: usize = 2000;
+const FENCE_OPEN: &str = "```ansi\n";
+const FENCE_CLOSE: &str = "```";
+
+pub struct GenericHelp<'a> {
+    pub command: &'a str,
+    pub desc: &'a str,
+    pub mode_and_channel: bool,
+    pub warn: bool,
+    pub run: bool,
+    pub aliasing_model: bool,
+    pub example_code: &'a str,
+}
+
+#[must_use]
+pub fn generic_help(help: GenericHelp<'_>) -> String {
+    let mut text = format!("{}\n\n```rust\n?{} {}\n```", help.desc, help.command, help.example_code.trim());
+    if help.mode_and_channel {
+        text.push_str("\n\nModes: debug, release. Channels: stable, beta, nightly");
+    }
+    if help.warn {
+        text.push_str("\n\nPass `warn=true` to show warnings");
+    }
+    if help.run {
+        text.push_str("\n\nPass `run=true` to also run the code");
+    }
+    if help.aliasing_model {
+        text.push_str("\n\nPass `aliasingmodel=stacked-borrows|tree-borrows` to select the aliasing model");
+    }
+    text
+}
+
+#[must_use]
+pub fn stub_message(_ctx: Context<'_>) -> String {
+    "Running code...".to_owned()
+}
+
+pub struct Flags {
+    pub channel: Channel,
+    pub edition: Edition,
+    pub mode: Mode,
+    pub warn: bool,
+}
+
+impl Default for Flags {
+    fn default() -> Self {
+        Self { channel: Channel::Stable, edition: Edition::E2021, mode: Mode::Debug, warn: false }
+    }
+}
+
+/// Parses the `key=value` flags shared by the playground commands (`channel`, `edition`,
+/// `mode`, `warn`), returning the parsed flags and a string describing any flags that failed
+/// to parse.
+#[must_use]
+pub fn parse_flags(args: KeyValueArgs) -> (Flags, String) {
+    let mut flags = Flags::default();
+    let mut errors = String::new();
+
+    for (key, value) in args.0 {
+        match key.as_str() {
+            "channel" => match value.as_str() {
+                "stable" => flags.channel = Channel::Stable,
+                "beta" => flags.channel = Channel::Beta,
+                "nightly" => flags.channel = Channel::Nightly,
+                _ => errors.push_str(&format!("Invalid channel `{value}`\n")),
+            },
+            "edition" => match value.as_str() {
+                "2015" => flags.edition = Edition::E2015,
+                "2018" => flags.edition = Edition::E2018,
+                "2021" => flags.edition = Edition::E2021,
+                _ => errors.push_str(&format!("Invalid edition `{value}`\n")),
+            },
+            "mode" => match value.as_str() {
+                "debug" => flags.mode = Mode::Debug,
+                "release" => flags.mode = Mode::Release,
+                _ => errors.push_str(&format!("Invalid mode `{value}`\n")),
+            },
+            "warn" => match value.parse() {
+                Ok(warn) => flags.warn = warn,
+                Err(_) => errors.push_str(&format!("Invalid value for `warn`: `{value}`\n")),
+            },
+            _ => errors.push_str(&format!("Unknown flag `{key}`\n")),
+        }
+    }
+
+    (flags, errors)
+}
+
+#[must_use]
+pub fn format_play_eval_stderr(stderr: &str, warn: bool) -> String {
+    if warn {
+        stderr.to_owned()
+    } else {
+        stderr.lines().filter(|line| !line.trim_start().starts_with("warning")).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Inserts `after_crate_attrs` right after any leading `#![...]` crate attributes in
+/// `user_code`, then appends `after_code` at the end, so generated boilerplate (imports, a
+/// `main` function, ...) doesn't end up above the user's own crate attributes.
+#[must_use]
+pub fn hoise_crate_attributes(user_code: &str, after_crate_attrs: &str, after_code: &str) -> String {
+    let split_at = user_code
+        .lines()
+        .take_while(|line| line.trim_start().starts_with("#!["))
+        .map(|line| line.len() + 1)
+        .sum::<usize>();
+    let (crate_attrs, rest) = user_code.split_at(split_at.min(user_code.len()));
+
+    format!("{crate_attrs}{after_crate_attrs}{rest}\n{after_code}")
+}
+
+/// Finds the largest char boundary in `s` at or before `index`, so a byte offset computed
+/// from a length budget can be used with `str::split_at` without risking a panic on a
+/// multi-byte character straddling that offset.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while index > 0 && !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Splits `text` along line boundaries into chunks that each fit within Discord's message
+/// limit once wrapped in their own ```` ```ansi ```` fence, flushing the current buffer
+/// whenever appending the next line (plus the closing fence) would cross the limit. A single
+/// line longer than the limit is hard-split rather than dropped.
+fn split_into_code_blocks(text: &str) -> Vec<String> {
+    let budget = DISCORD_MESSAGE_LIMIT - FENCE_OPEN.len() - FENCE_CLOSE.len();
+    let mut chunks = Vec::new();
+    let mut buffer = String::new();
+
+    for line in text.lines() {
+        let mut remaining = line;
+        loop {
+            if buffer.is_empty() && remaining.len() > budget {
+                let split_at = match floor_char_boundary(remaining, budget) {
+                    0 => remaining.chars().next().map_or(remaining.len(), char::len_utf8),
+                    split_at => split_at,
+                };
+                let (head, tail) = remaining.split_at(split_at);
+                chunks.push(format!("{FENCE_OPEN}{head}{FENCE_CLOSE}"));
+                remaining = tail;
+                continue;
+            }
+
+            let needed = remaining.len() + usize::from(!buffer.is_empty());
+            if !buffer.is_empty() && buffer.len() + needed > budget {
+                chunks.push(format!("{FENCE_OPEN}{buffer}{FENCE_CLOSE}"));
+                buffer.clear();
+                continue;
+            }
+
+            if !buffer.is_empty() {
+                buffer.push('\n');
+            }
+            buffer.push_str(remaining);
+            break;
+        }
+    }
+
+    if !buffer.is_empty() || chunks.is_empty() {
+        chunks.push(format!("{FENCE_OPEN}{buffer}{FENCE_CLOSE}"));
+    }
+
+    chunks
+}
+
+pub async fn send_reply(
+    ctx: Context<'_>,
+    result: PlayResult,
+    _code: &str,
+    flags: &Flags,
+    flag_parse_errors: &str,
+) -> Result<(), Error> {
+    let mut text = String::new();
+    if !flag_parse_errors.is_empty() {
+        text.push_str(flag_parse_errors);
+        text.push('\n');
+    }
+    if !result.stderr.is_empty() {
+        text.push_str(&result.stderr);
+    }
+    if !result.stdout.is_empty() {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&result.stdout);
+    }
+    if text.is_empty() {
+        text.push_str("(no output)");
+    }
+
+    for chunk in split_into_code_blocks(&text) {
+        ctx.say(chunk).await?;
+    }
+
+    Ok(())
+}
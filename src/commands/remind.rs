@@ -0,0 +1,73 @@
+use crate::types::Context;
+use anyhow::{anyhow, Error};
+use std::time::Duration;
+
+/// Set a reminder that's delivered back to you in this channel once the duration elapses
+#[poise::command(prefix_command, slash_command, track_edits, category = "Utilities")]
+pub async fn remind(
+    ctx: Context<'_>,
+    #[description = "When to remind you, e.g. `10m`, `2h30m`, `1d`"] duration: String,
+    #[description = "What to remind you about"]
+    #[rest]
+    message: String,
+) -> Result<(), Error> {
+    let duration =
+        parse_duration(&duration).ok_or_else(|| anyhow!("couldn't parse `{duration}` as a duration"))?;
+    let due_at = chrono::Utc::now() + chrono::Duration::from_std(duration)?;
+
+    // Runtime-checked rather than `query!` — see `Data::new` for why.
+    sqlx::query("INSERT INTO reminders (user_id, channel_id, guild_id, due_at, message) VALUES ($1, $2, $3, $4, $5)")
+        .bind(ctx.author().id.get() as i64)
+        .bind(ctx.channel_id().get() as i64)
+        .bind(ctx.guild_id().map(|id| id.get() as i64))
+        .bind(due_at)
+        .bind(&message)
+        .execute(&ctx.data().db)
+        .await?;
+
+    ctx.say(format!("Alright, I'll remind you about that <t:{}:R>", due_at.timestamp())).await?;
+    Ok(())
+}
+
+/// Parses a human duration string like `10m`, `2h30m` or `1d` into a [`Duration`] by summing
+/// each `<number><unit>` pair, where `unit` is one of `s`/`m`/`h`/`d`/`w`. Returns `None` if the
+/// input is empty, contains no recognized unit, or can't be tokenized into such pairs.
+fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+
+    let mut chars = input.chars().peekable();
+    let mut total_seconds: u64 = 0;
+    let mut saw_unit = false;
+
+    while chars.peek().is_some() {
+        let mut digits = String::new();
+        while let Some(&c) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            digits.push(c);
+            chars.next();
+        }
+        if digits.is_empty() {
+            return None;
+        }
+        let amount: u64 = digits.parse().ok()?;
+
+        let seconds_per_unit = match chars.next()? {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 60 * 60 * 24,
+            'w' => 60 * 60 * 24 * 7,
+            _ => return None,
+        };
+
+        total_seconds = total_seconds.checked_add(amount.checked_mul(seconds_per_unit)?)?;
+        saw_unit = true;
+    }
+
+    saw_unit.then(|| Duration::from_secs(total_seconds))
+}
@@ -0,0 +1,45 @@
+use crate::checks::check_is_moderator;
+use crate::types::Context;
+use anyhow::{anyhow, Error};
+
+/// Manage per-guild bot settings
+#[poise::command(slash_command, prefix_command, subcommands("set"), category = "Utilities", check = "check_is_moderator")]
+pub async fn settings(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Change a setting for this server
+#[poise::command(slash_command, prefix_command, subcommands("set_prefix"), check = "check_is_moderator")]
+async fn set(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Add an additional prefix the bot will respond to in this server
+#[poise::command(rename = "prefix", slash_command, prefix_command, check = "check_is_moderator")]
+async fn set_prefix(
+    ctx: Context<'_>,
+    #[description = "Prefix to add"] prefix: String,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or_else(|| anyhow!("this command can only be used in a server"))?;
+
+    let prefix = prefix.trim();
+    if prefix.is_empty() {
+        ctx.say("Prefix can't be empty").await?;
+        return Ok(());
+    }
+
+    // Runtime-checked rather than `query!` — see `Data::new` for why.
+    sqlx::query(
+        "INSERT INTO guild_settings (guild_id, prefixes) VALUES ($1, ARRAY[$2])
+         ON CONFLICT (guild_id) DO UPDATE SET prefixes = array_append(guild_settings.prefixes, $2)",
+    )
+    .bind(guild_id.get() as i64)
+    .bind(prefix)
+    .execute(&ctx.data().db)
+    .await?;
+
+    ctx.data().guild_settings.write().await.entry(guild_id).or_default().prefixes.push(prefix.to_owned());
+
+    ctx.say(format!("Added `{prefix}` as an additional prefix for this server")).await?;
+    Ok(())
+}
@@ -11,6 +11,7 @@
 mod checks;
 mod commands;
 mod helpers;
+mod reminders;
 mod types;
 
 use anyhow::Error;
@@ -24,6 +25,7 @@ use types::Data;
 #[derive(Deserialize)]
 struct Config {
     discord: DiscordConfig,
+    database: DatabaseConfig,
 }
 
 #[derive(Deserialize)]
@@ -31,6 +33,15 @@ struct DiscordConfig {
     token: String,
     guild_id: u64,
     application_id: u64,
+    /// Role that, in addition to the `BAN_MEMBERS`/`MANAGE_MESSAGES`/`ADMINISTRATOR`
+    /// permissions, is treated as granting access to moderator-only commands.
+    #[serde(default)]
+    moderator_role_id: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct DatabaseConfig {
+    url: String,
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -54,7 +65,7 @@ code here
     let framework = poise::Framework::builder()
         .setup(move |ctx, ready, framework| {
             Box::pin(async move {
-                let data = Data::new(&config);
+                let data = Data::new(&config).await?;
 
                 debug!("Registering commands...");
                 poise::builtins::register_in_guild(ctx, &framework.options().commands, data.discord_guild_id).await?;
@@ -62,6 +73,9 @@ code here
                 debug!("Setting activity text");
                 ctx.set_activity(Some(serenity::ActivityData::listening("/help")));
 
+                debug!("Spawning reminder scheduler");
+                tokio::spawn(reminders::run_scheduler(data.db.clone(), ctx.http.clone()));
+
                 info!("rustbot logged in as {}", ready.user.name);
                 Ok(data)
             })
@@ -84,6 +98,8 @@ code here
                 commands::utilities::cleanup(),
                 commands::utilities::ban(),
                 commands::utilities::selftimeout(),
+                commands::remind::remind(),
+                commands::settings::settings(),
                 commands::thread_pin::thread_pin(),
                 commands::playground::play(),
                 commands::playground::playwarn(),
@@ -115,6 +131,22 @@ code here
                 edit_tracker: Some(Arc::new(poise::EditTracker::for_timespan(
                     Duration::from_secs(60 * 5), // 5 minutes
                 ))),
+                // Lets each guild register its own additional prefixes on top of the ones above,
+                // via `/settings set prefix`.
+                dynamic_prefix: Some(|ctx| {
+                    Box::pin(async move {
+                        let Some(guild_id) = ctx.guild_id else {
+                            return Ok(None);
+                        };
+
+                        let guild_settings = ctx.data.guild_settings.read().await;
+                        let Some(settings) = guild_settings.get(&guild_id) else {
+                            return Ok(None);
+                        };
+
+                        Ok(settings.prefixes.iter().find(|prefix| ctx.msg.content.starts_with(prefix.as_str())).cloned())
+                    })
+                }),
                 ..Default::default()
             },
             // The global error handler for all error cases that may occur
@@ -0,0 +1,79 @@
+//! Background scheduler that delivers reminders set via the `/remind` command.
+//!
+//! Pending reminders live only in the `reminders` table, so delivery survives restarts: the
+//! scheduler simply polls the table instead of tracking anything in memory.
+
+use poise::serenity_prelude as serenity;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// Deliveries of a single reminder that can fail (channel deleted, permissions revoked, ...)
+/// before it's given up on and dropped, so a permanently undeliverable reminder can't wedge
+/// the scheduler into retrying (and warning) forever.
+const MAX_DELIVERY_ATTEMPTS: i32 = 3;
+
+#[derive(sqlx::FromRow)]
+struct DueReminder {
+    id: i64,
+    user_id: i64,
+    channel_id: i64,
+    message: String,
+    attempts: i32,
+}
+
+pub async fn run_scheduler(db: sqlx::PgPool, http: Arc<serenity::Http>) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(e) = deliver_due_reminders(&db, &http).await {
+            warn!("failed to deliver reminders: {}", e);
+        }
+    }
+}
+
+async fn deliver_due_reminders(db: &sqlx::PgPool, http: &serenity::Http) -> anyhow::Result<()> {
+    // Runtime-checked rather than `query_as!` — see `Data::new` for why.
+    let due =
+        sqlx::query_as::<_, DueReminder>("SELECT id, user_id, channel_id, message, attempts FROM reminders WHERE due_at <= now()")
+            .fetch_all(db)
+            .await?;
+
+    for reminder in due {
+        let channel_id = serenity::ChannelId::new(reminder.channel_id as u64);
+        let user_id = serenity::UserId::new(reminder.user_id as u64);
+
+        let send_result = channel_id
+            .send_message(
+                http,
+                serenity::CreateMessage::new()
+                    .content(format!("<@{user_id}> {}", reminder.message))
+                    .allowed_mentions(serenity::CreateAllowedMentions::new().users([user_id])),
+            )
+            .await;
+        if let Err(e) = send_result {
+            let attempts = reminder.attempts + 1;
+            if attempts >= MAX_DELIVERY_ATTEMPTS {
+                warn!("giving up on reminder {} after {} failed attempts ({})", reminder.id, attempts, e);
+                if let Err(e) = sqlx::query("DELETE FROM reminders WHERE id = $1").bind(reminder.id).execute(db).await {
+                    warn!("failed to delete abandoned reminder {}: {}", reminder.id, e);
+                }
+            } else {
+                warn!("failed to send reminder {} (attempt {}/{}): {}", reminder.id, attempts, MAX_DELIVERY_ATTEMPTS, e);
+                if let Err(e) =
+                    sqlx::query("UPDATE reminders SET attempts = $2 WHERE id = $1").bind(reminder.id).bind(attempts).execute(db).await
+                {
+                    warn!("failed to record delivery attempt for reminder {}: {}", reminder.id, e);
+                }
+            }
+            continue;
+        }
+
+        if let Err(e) = sqlx::query("DELETE FROM reminders WHERE id = $1").bind(reminder.id).execute(db).await {
+            warn!("failed to delete delivered reminder {}: {}", reminder.id, e);
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{commands, Config};
 use anyhow::Error;
 use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Data {
@@ -9,17 +10,74 @@ pub struct Data {
     pub bot_start_time: std::time::Instant,
     pub http: reqwest::Client,
     pub godbolt_metadata: std::sync::Mutex<commands::godbolt::GodboltMetadata>,
+    pub moderator_role_id: Option<serenity::RoleId>,
+    pub db: sqlx::PgPool,
+    pub guild_settings: tokio::sync::RwLock<HashMap<serenity::GuildId, GuildSettings>>,
+}
+
+/// Per-guild overrides for bot behavior, backed by the `guild_settings` table and mutated by
+/// the `/settings` commands. Kept in memory so prefix resolution never has to hit the
+/// database.
+#[derive(Debug, Clone, Default)]
+pub struct GuildSettings {
+    pub prefixes: Vec<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct GuildSettingsRow {
+    guild_id: i64,
+    prefixes: Vec<String>,
 }
 
 impl Data {
-    pub fn new(config: &Config) -> Self {
-        Self {
+    pub async fn new(config: &Config) -> anyhow::Result<Self> {
+        let db = sqlx::postgres::PgPoolOptions::new().max_connections(5).connect(&config.database.url).await?;
+
+        // These tables are only ever created here, at runtime, rather than via a migrations
+        // directory, and this repo has no `sqlx prepare` offline cache checked in either — so
+        // every query against them below uses the runtime-checked `sqlx::query`/`query_as`
+        // rather than the compile-time-checked `query!`/`query_as!` macros, which would
+        // otherwise need a live, already-migrated database just to build.
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS reminders (
+                id BIGSERIAL PRIMARY KEY,
+                user_id BIGINT NOT NULL,
+                channel_id BIGINT NOT NULL,
+                guild_id BIGINT,
+                due_at TIMESTAMPTZ NOT NULL,
+                message TEXT NOT NULL,
+                attempts INTEGER NOT NULL DEFAULT 0
+            )",
+        )
+        .execute(&db)
+        .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS guild_settings (
+                guild_id BIGINT PRIMARY KEY,
+                prefixes TEXT[] NOT NULL DEFAULT '{}'
+            )",
+        )
+        .execute(&db)
+        .await?;
+
+        let guild_settings = sqlx::query_as::<_, GuildSettingsRow>("SELECT guild_id, prefixes FROM guild_settings")
+            .fetch_all(&db)
+            .await?
+            .into_iter()
+            .map(|row| (serenity::GuildId::new(row.guild_id as u64), GuildSettings { prefixes: row.prefixes }))
+            .collect();
+
+        Ok(Self {
             discord_guild_id: config.discord.guild_id.into(),
             application_id: config.discord.application_id.into(),
             bot_start_time: std::time::Instant::now(),
             http: reqwest::Client::new(),
             godbolt_metadata: std::sync::Mutex::new(commands::godbolt::GodboltMetadata::default()),
-        }
+            moderator_role_id: config.discord.moderator_role_id.map(serenity::RoleId::from),
+            db,
+            guild_settings: tokio::sync::RwLock::new(guild_settings),
+        })
     }
 }
 